@@ -0,0 +1,456 @@
+mod errors;
+mod random;
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+pub use errors::GenerateError;
+use random::Rng;
+
+/// Value written in place of a field when an error is injected.
+const INJECTED_ERROR: &str = "#ERR";
+
+/// Default delimiter function for generated CSV files.
+fn default_delimiter() -> String {
+    ",".to_string()
+}
+
+/// Default probability for boolean fields.
+fn default_probability() -> f64 {
+    0.5
+}
+
+/// Default first value of sequence fields.
+fn default_start() -> i64 {
+    1
+}
+
+/// Format of the generated file.
+///
+/// Formats use the same `type` names as their readers, so a fixture can be read back with the matching reader.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum FixtureFormat {
+    /// CSV file with a header row
+    Csv {
+        /// The delimiter used in the CSV file. Defaults to a comma (`,`).
+        #[serde(default = "default_delimiter")]
+        delimiter: String,
+    },
+    /// One json object per line
+    JsonStream,
+}
+
+/// Kind of values generated for a field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum FieldKind {
+    /// Integers uniformly distributed between `min` and `max`, both included
+    Integer { min: i64, max: i64 },
+    /// Floats uniformly distributed between `min` and `max`
+    Float { min: f64, max: f64 },
+    /// Floats following a normal distribution
+    Normal { mean: f64, std_dev: f64 },
+    /// Booleans being true with the given probability. Defaults to `0.5`.
+    Boolean {
+        #[serde(default = "default_probability")]
+        probability: f64,
+    },
+    /// Values picked from a list, uniformly or according to `weights`
+    Choice {
+        values: Vec<Value>,
+        #[serde(default)]
+        weights: Vec<f64>,
+    },
+    /// Lowercase ascii strings whose length is between `min_length` and `max_length`
+    Text {
+        min_length: usize,
+        max_length: usize,
+    },
+    /// Incrementing integers, starting at `start`. Defaults to `1`.
+    Sequence {
+        #[serde(default = "default_start")]
+        start: i64,
+    },
+}
+
+/// Description of a generated field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldSpec {
+    /// Name of the field
+    pub name: String,
+
+    /// Kind of values to generate
+    #[serde(flatten)]
+    pub kind: FieldKind,
+}
+
+/// Struct describing a fixture file to generate.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustifile::generate::FixtureSpec;
+///
+/// let spec: FixtureSpec = serde_json::from_str(r#"{
+///     "format": {"type": "csv"},
+///     "rows": 1000,
+///     "error_rate": 0.01,
+///     "fields": [
+///         {"name": "id", "type": "sequence"},
+///         {"name": "price", "type": "normal", "mean": 20.0, "std_dev": 5.0},
+///         {"name": "country", "type": "choice", "values": ["FR", "US"], "weights": [1.0, 3.0]}
+///     ]
+/// }"#).unwrap();
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureSpec {
+    /// Format of the generated file
+    pub format: FixtureFormat,
+
+    /// Number of rows to generate
+    pub rows: usize,
+
+    /// Fields of each row
+    pub fields: Vec<FieldSpec>,
+
+    /// Probability for each row to have one of its fields replaced by an invalid value. Defaults to `0`.
+    #[serde(default)]
+    pub error_rate: f64,
+
+    /// Seed of the random generator. The same spec and seed always generate the same file.
+    #[serde(default)]
+    pub seed: u64,
+}
+
+impl FixtureSpec {
+    /// Checks that the spec can be used to generate values.
+    fn validate(&self) -> Result<(), GenerateError> {
+        if self.fields.is_empty() {
+            return Err(GenerateError::InvalidSpec("at least one field is required"));
+        }
+        if !(0.0..=1.0).contains(&self.error_rate) {
+            return Err(GenerateError::InvalidSpec(
+                "error_rate must be between 0 and 1",
+            ));
+        }
+        if let FixtureFormat::Csv { delimiter } = &self.format
+            && delimiter.len() != 1
+        {
+            return Err(GenerateError::InvalidSpec(
+                "csv delimiter must be a single byte",
+            ));
+        }
+
+        for field in &self.fields {
+            match &field.kind {
+                FieldKind::Integer { min, max } if min > max => {
+                    return Err(GenerateError::InvalidSpec(
+                        "integer min is greater than max",
+                    ));
+                }
+                FieldKind::Float { min, max } if min > max => {
+                    return Err(GenerateError::InvalidSpec("float min is greater than max"));
+                }
+                FieldKind::Normal { std_dev, .. } if *std_dev < 0.0 => {
+                    return Err(GenerateError::InvalidSpec(
+                        "normal std_dev must be positive",
+                    ));
+                }
+                FieldKind::Boolean { probability } if !(0.0..=1.0).contains(probability) => {
+                    return Err(GenerateError::InvalidSpec(
+                        "boolean probability must be between 0 and 1",
+                    ));
+                }
+                FieldKind::Choice { values, .. } if values.is_empty() => {
+                    return Err(GenerateError::InvalidSpec("choice values are empty"));
+                }
+                FieldKind::Choice { values, weights }
+                    if !weights.is_empty()
+                        && (weights.len() != values.len()
+                            || weights.iter().any(|w| *w < 0.0)
+                            || weights.iter().sum::<f64>() <= 0.0) =>
+                {
+                    return Err(GenerateError::InvalidSpec(
+                        "choice weights must be positive and match values",
+                    ));
+                }
+                FieldKind::Text {
+                    min_length,
+                    max_length,
+                } if min_length > max_length => {
+                    return Err(GenerateError::InvalidSpec(
+                        "text min_length is greater than max_length",
+                    ));
+                }
+                FieldKind::Sequence { start }
+                    if i64::try_from(self.rows.saturating_sub(1))
+                        .ok()
+                        .and_then(|offset| start.checked_add(offset))
+                        .is_none() =>
+                {
+                    return Err(GenerateError::InvalidSpec(
+                        "sequence overflows for the number of rows",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Generates a single value for a field.
+fn generate_value(kind: &FieldKind, row: usize, rng: &mut Rng) -> Value {
+    match kind {
+        FieldKind::Integer { min, max } => Value::from(rng.range_i64(*min, *max)),
+        FieldKind::Float { min, max } => float_value(min + rng.next_f64() * (max - min)),
+        FieldKind::Normal { mean, std_dev } => float_value(rng.normal(*mean, *std_dev)),
+        FieldKind::Boolean { probability } => Value::Bool(rng.next_f64() < *probability),
+        FieldKind::Choice { values, weights } if weights.is_empty() => {
+            values[rng.range_i64(0, values.len() as i64 - 1) as usize].clone()
+        }
+        FieldKind::Choice { values, weights } => {
+            let mut target = rng.next_f64() * weights.iter().sum::<f64>();
+            for (value, weight) in values.iter().zip(weights) {
+                if target < *weight {
+                    return value.clone();
+                }
+                target -= weight;
+            }
+            values[values.len() - 1].clone()
+        }
+        FieldKind::Text {
+            min_length,
+            max_length,
+        } => {
+            let len = rng.range_i64(*min_length as i64, *max_length as i64);
+            Value::String(
+                (0..len)
+                    .map(|_| (b'a' + rng.range_i64(0, 25) as u8) as char)
+                    .collect(),
+            )
+        }
+        FieldKind::Sequence { start } => Value::from(start + row as i64),
+    }
+}
+
+/// Converts a float to a json value, using `null` for values json cannot represent.
+fn float_value(value: f64) -> Value {
+    Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+/// Converts a json value to a CSV cell. `null` values are written as empty cells.
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Generates the rows described by a spec, each row holding its values in the order of the spec fields.
+fn generate_rows(spec: &FixtureSpec) -> impl Iterator<Item = Vec<Value>> + '_ {
+    let mut rng = Rng::new(spec.seed);
+
+    (0..spec.rows).map(move |row| {
+        let mut values: Vec<Value> = spec
+            .fields
+            .iter()
+            .map(|field| generate_value(&field.kind, row, &mut rng))
+            .collect();
+
+        if spec.error_rate > 0.0 && rng.next_f64() < spec.error_rate {
+            let index = rng.range_i64(0, values.len() as i64 - 1) as usize;
+            values[index] = Value::String(INJECTED_ERROR.to_string());
+        }
+
+        values
+    })
+}
+
+/// Generates a synthetic fixture file from a spec.
+///
+/// Rows are built from the spec fields, each one having a probability of `error_rate` to get one of its
+/// fields replaced by the `#ERR` string, so pipelines can be load-tested against invalid values.
+///
+/// # Returns
+///
+/// * `Result<usize, GenerateError>` - Returns the number of rows written, or an error if the spec is invalid or the file cannot be written.
+pub fn generate<P: AsRef<Path>>(spec: &FixtureSpec, path: P) -> Result<usize, GenerateError> {
+    spec.validate()?;
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut rows = 0;
+
+    match &spec.format {
+        FixtureFormat::Csv { delimiter } => {
+            let mut writer = csv::WriterBuilder::new()
+                .delimiter(delimiter.as_bytes()[0])
+                .from_writer(file);
+
+            writer.write_record(spec.fields.iter().map(|f| &f.name))?;
+            for values in generate_rows(spec) {
+                writer.write_record(values.iter().map(csv_cell))?;
+                rows += 1;
+            }
+            writer.flush()?;
+        }
+        FixtureFormat::JsonStream => {
+            let mut writer = file;
+            for values in generate_rows(spec) {
+                let record: Map<String, Value> = spec
+                    .fields
+                    .iter()
+                    .map(|f| f.name.clone())
+                    .zip(values)
+                    .collect();
+                serde_json::to_writer(&mut writer, &record)?;
+                writer.write_all(b"\n")?;
+                rows += 1;
+            }
+            writer.flush()?;
+        }
+    }
+
+    tracing::debug!("Generated {} rows with spec : {:?}", rows, spec);
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+    use crate::readers::{FileReader, ReaderError};
+
+    fn get_spec(format: Value, error_rate: f64) -> FixtureSpec {
+        serde_json::from_value(json!({
+            "format": format,
+            "rows": 200,
+            "error_rate": error_rate,
+            "seed": 42,
+            "fields": [
+                {"name": "id", "type": "sequence"},
+                {"name": "quantity", "type": "integer", "min": 1, "max": 10},
+                {"name": "price", "type": "float", "min": 0.0, "max": 100.0},
+                {"name": "in_stock", "type": "boolean", "probability": 0.8},
+                {"name": "country", "type": "choice", "values": ["FR", "US"]},
+                {"name": "name", "type": "text", "min_length": 3, "max_length": 8}
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn read_back(reader: Value) -> Vec<Value> {
+        let mut reader: Box<dyn FileReader> = serde_json::from_value(reader).unwrap();
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        results.into_iter().flatten().collect()
+    }
+
+    #[test]
+    fn test_generate_csv() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let rows = generate(&get_spec(json!({"type": "csv"}), 0.0), path).unwrap();
+        assert_eq!(rows, 200);
+
+        let records = read_back(json!({"type": "csv", "file_path": path}));
+        assert_eq!(records.len(), 200);
+        assert_eq!(records[0]["id"], json!(1));
+        assert_eq!(records[199]["id"], json!(200));
+        for record in records {
+            assert!((1..=10).contains(&record["quantity"].as_i64().unwrap()));
+            assert!((0.0..100.0).contains(&record["price"].as_f64().unwrap()));
+            assert!(record["in_stock"].is_boolean());
+            assert!(["FR", "US"].contains(&record["country"].as_str().unwrap()));
+            assert!((3..=8).contains(&record["name"].as_str().unwrap().len()));
+        }
+    }
+
+    #[test]
+    fn test_generate_jsonstream_with_errors() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        generate(&get_spec(json!({"type": "jsonstream"}), 0.5), path).unwrap();
+
+        let records = read_back(json!({"type": "jsonstream", "file_path": path}));
+        assert_eq!(records.len(), 200);
+
+        let errors = records
+            .iter()
+            .filter(|r| r.as_object().unwrap().values().any(|v| v == INJECTED_ERROR))
+            .count();
+        assert!(
+            errors > 50 && errors < 150,
+            "Unexpected error count {errors}"
+        );
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        let first = NamedTempFile::new().unwrap();
+        let second = NamedTempFile::new().unwrap();
+        let spec = get_spec(json!({"type": "jsonstream"}), 0.1);
+
+        generate(&spec, first.path()).unwrap();
+        generate(&spec, second.path()).unwrap();
+
+        assert_eq!(
+            std::fs::read(first.path()).unwrap(),
+            std::fs::read(second.path()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_invalid_spec() {
+        let file = NamedTempFile::new().unwrap();
+        let mut spec = get_spec(json!({"type": "csv"}), 0.0);
+        spec.fields.push(FieldSpec {
+            name: "broken".to_string(),
+            kind: FieldKind::Integer { min: 10, max: 1 },
+        });
+
+        assert!(matches!(
+            generate(&spec, file.path()),
+            Err(GenerateError::InvalidSpec(_))
+        ));
+    }
+
+    #[test]
+    fn test_generate_sequence_overflow() {
+        let file = NamedTempFile::new().unwrap();
+        let mut spec = get_spec(json!({"type": "csv"}), 0.0);
+        spec.rows = 3;
+        spec.fields = vec![FieldSpec {
+            name: "id".to_string(),
+            kind: FieldKind::Sequence {
+                start: i64::MAX - 1,
+            },
+        }];
+
+        assert!(matches!(
+            generate(&spec, file.path()),
+            Err(GenerateError::InvalidSpec(_))
+        ));
+
+        spec.rows = 2;
+        assert_eq!(generate(&spec, file.path()).unwrap(), 2);
+    }
+}
@@ -0,0 +1,41 @@
+/// Small deterministic pseudo random generator (SplitMix64).
+///
+/// Fixtures only need reproducible, well spread values, not cryptographic randomness.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed. The same seed always yields the same values.
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Returns the next 64 random bits.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a float uniformly distributed in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns an integer uniformly distributed in `[min, max]`.
+    pub(crate) fn range_i64(&mut self, min: i64, max: i64) -> i64 {
+        let span = (max as i128 - min as i128 + 1) as u128;
+        (min as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// Returns a float following a normal distribution, using the Box-Muller transform.
+    pub(crate) fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = 1.0 - self.next_f64(); // Avoid ln(0)
+        let u2 = self.next_f64();
+        mean + std_dev * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
@@ -1 +1,5 @@
+pub mod generate;
 pub mod readers;
+pub mod snapshots;
+pub mod transforms;
+pub mod writers;
@@ -3,7 +3,10 @@ use std::{fs::File, io::BufReader};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use super::{FileReader, ReaderError};
+use super::{FileReader, ReaderError, check_readable, detect_dialect};
+
+/// Delimiter value asking the reader to infer the file dialect with [`detect_dialect`].
+const AUTO_DELIMITER: &str = "auto";
 
 /// Default delimiter function for the CSV reader.
 ///
@@ -18,6 +21,8 @@ fn default_delimiter() -> String {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CsvReader {
     /// The delimiter used in the CSV file. Defaults to a comma (`,`).
+    ///
+    /// When set to `auto`, the delimiter, quote character and header presence are inferred from the file content.
     #[serde(default = "default_delimiter")]
     delimiter: String,
 
@@ -33,6 +38,10 @@ pub struct CsvReader {
     #[serde(skip)]
     _reader: Option<csv::Reader<BufReader<File>>>,
 
+    /// Generated headers used when the file has no header row. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _headers: Option<csv::StringRecord>,
+
     /// Indicate if the reader has already been initialized
     #[serde(default)]
     _initialized: bool,
@@ -49,14 +58,30 @@ impl CsvReader {
     fn init_reader(&mut self) -> Result<(), ReaderError> {
         let buf_reader = BufReader::new(File::open(&self.file_path)?);
 
-        let reader = csv::ReaderBuilder::new()
-            .flexible(self.flexible)
-            .delimiter(if self.delimiter.is_empty() {
+        let mut builder = csv::ReaderBuilder::new();
+        builder.flexible(self.flexible);
+
+        if self.delimiter == AUTO_DELIMITER {
+            // Line terminator is not forwarded as the reader already accepts `\n`, `\r\n` and `\r`
+            let dialect = detect_dialect(&self.file_path)?;
+            builder
+                .delimiter(dialect.delimiter)
+                .quote(dialect.quote)
+                .has_headers(dialect.has_headers);
+        } else {
+            builder.delimiter(if self.delimiter.is_empty() {
                 b',' // Default to comma if empty
             } else {
                 self.delimiter.as_bytes()[0] // Only use first byte
-            })
-            .from_reader(buf_reader);
+            });
+        }
+
+        let reader = builder.from_reader(buf_reader);
+
+        if !reader.has_headers() {
+            // Columns are named `column_1`, `column_2`... as records are read, as flexible records can be wider
+            self._headers = Some(csv::StringRecord::new());
+        }
 
         tracing::debug!("Initialized csv reader with config : {:?}", self);
 
@@ -99,12 +124,24 @@ impl FileReader for CsvReader {
             return None;
         }
 
-        match &mut self._reader {
-            Some(reader) => reader.deserialize().next().map(|result| {
+        match (&mut self._reader, &mut self._headers) {
+            (Some(reader), Some(headers)) => reader.records().next().map(|result| {
+                let result = result?;
+                for i in headers.len() + 1..=result.len() {
+                    headers.push_field(&format!("column_{i}"));
+                }
+                let record: Map<String, Value> = if result.len() < headers.len() {
+                    result.deserialize(Some(&headers.iter().take(result.len()).collect()))?
+                } else {
+                    result.deserialize(Some(headers))?
+                };
+                Ok(Value::Object(record))
+            }),
+            (Some(reader), None) => reader.deserialize().next().map(|result| {
                 let record: Map<String, Value> = result?;
                 Ok(Value::Object(record))
             }),
-            None => {
+            (None, _) => {
                 tracing::error!("Cannot initialize reader");
                 Some(Err(ReaderError::InitializationError(
                     "Failed to initialize reader",
@@ -112,6 +149,11 @@ impl FileReader for CsvReader {
             }
         }
     }
+
+    /// Checks that the file exists and is readable.
+    fn preflight(&self) -> Result<(), ReaderError> {
+        check_readable(&self.file_path)
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +171,7 @@ mod tests {
             flexible: false,
             file_path: format!("{}/examples/uspop.csv", env!("CARGO_MANIFEST_DIR")),
             _reader: None,
+            _headers: None,
             _initialized: false,
         };
 
@@ -175,6 +218,7 @@ mod tests {
             flexible: true,
             file_path: path,
             _reader: None,
+            _headers: None,
             _initialized: false,
         };
 
@@ -199,6 +243,7 @@ mod tests {
             flexible: false,
             file_path: path,
             _reader: None,
+            _headers: None,
             _initialized: false,
         };
 
@@ -230,6 +275,7 @@ mod tests {
             flexible: false,
             file_path: path,
             _reader: None,
+            _headers: None,
             _initialized: false,
         };
 
@@ -248,6 +294,7 @@ mod tests {
             flexible: false,
             file_path: "nonexistent_file.csv".to_string(),
             _reader: None,
+            _headers: None,
             _initialized: false,
         };
 
@@ -263,4 +310,133 @@ mod tests {
         // Subsequent reads should return None
         assert!(reader.read_item().is_none(), "Expected None after error");
     }
+
+    #[test]
+    fn test_auto_delimiter() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "City;State;Population").unwrap();
+        writeln!(file, "New York;NY;8419000").unwrap();
+        writeln!(file, "\"Los Angeles; CA\";CA;3971000").unwrap();
+
+        let path = file.path().to_str().unwrap().to_string();
+        let mut reader = CsvReader {
+            delimiter: "auto".to_string(),
+            flexible: false,
+            file_path: path,
+            _reader: None,
+            _headers: None,
+            _initialized: false,
+        };
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let valid_results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(valid_results.len(), 2);
+        assert_eq!(
+            valid_results[1]["City"],
+            Value::String("Los Angeles; CA".to_string())
+        );
+        assert_eq!(
+            valid_results[1]["Population"],
+            Value::Number(Number::from_u128(3971000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_auto_delimiter_without_headers() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "1|foo|true").unwrap();
+        writeln!(file, "2|bar|false").unwrap();
+
+        let path = file.path().to_str().unwrap().to_string();
+        let mut reader = CsvReader {
+            delimiter: "auto".to_string(),
+            flexible: false,
+            file_path: path,
+            _reader: None,
+            _headers: None,
+            _initialized: false,
+        };
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let valid_results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(valid_results.len(), 2);
+        assert_eq!(valid_results[0]["column_1"], Value::Number(Number::from(1)));
+        assert_eq!(
+            valid_results[0]["column_2"],
+            Value::String("foo".to_string())
+        );
+        assert_eq!(valid_results[1]["column_3"], Value::Bool(false));
+    }
+
+    #[test]
+    fn test_auto_delimiter_flexible_without_headers() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "1|2").unwrap();
+        writeln!(file, "3|4|5").unwrap();
+        writeln!(file, "6|7").unwrap();
+        writeln!(file, "8|9").unwrap();
+
+        let path = file.path().to_str().unwrap().to_string();
+        let mut reader = CsvReader {
+            delimiter: "auto".to_string(),
+            flexible: true,
+            file_path: path,
+            _reader: None,
+            _headers: None,
+            _initialized: false,
+        };
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let valid_results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(valid_results.len(), 4);
+        assert_eq!(
+            valid_results[1],
+            serde_json::json!({"column_1": 3, "column_2": 4, "column_3": 5})
+        );
+        assert_eq!(
+            valid_results[2],
+            serde_json::json!({"column_1": 6, "column_2": 7})
+        );
+    }
+
+    #[test]
+    fn test_preflight() {
+        let reader = |file_path: String| CsvReader {
+            delimiter: ",".to_string(),
+            flexible: false,
+            file_path,
+            _reader: None,
+            _headers: None,
+            _initialized: false,
+        };
+
+        let mut valid = reader(format!("{}/examples/uspop.csv", env!("CARGO_MANIFEST_DIR")));
+        assert!(valid.preflight().is_ok());
+        assert!(
+            valid._reader.is_none(),
+            "Preflight should not open the reader"
+        );
+        assert_eq!(valid.read_item().into_iter().flatten().count(), 1);
+
+        assert!(matches!(
+            reader("nonexistent_file.csv".to_string()).preflight(),
+            Err(ReaderError::IoError(_))
+        ));
+        assert!(matches!(
+            reader(env!("CARGO_MANIFEST_DIR").to_string()).preflight(),
+            Err(ReaderError::PreflightError(_))
+        ));
+    }
 }
@@ -10,4 +10,12 @@ pub enum ReaderError {
     IoError(#[from] std::io::Error),
     #[error("Reader error: {0}")]
     InitializationError(&'static str),
+    #[error("Cannot detect CSV dialect: {0}")]
+    DialectError(&'static str),
+    #[error("Plugin error: {0}")]
+    PluginError(String),
+    #[error("Invalid SWIFT message: {0}")]
+    SwiftError(&'static str),
+    #[error("Preflight failed: {0}")]
+    PreflightError(String),
 }
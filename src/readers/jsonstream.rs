@@ -7,11 +7,17 @@ use std::{
 use serde::{Deserialize, Serialize};
 use serde_json::{Deserializer, Value};
 
-use super::{FileReader, ReaderError};
+use super::{FileReader, ReaderError, check_readable};
+
+/// Shared iterator over the json values of the stream.
+type ValueStream = Arc<Mutex<dyn Iterator<Item = Result<Value, serde_json::Error>>>>;
 
 /// A struct representing a JSON Stream reader.
 ///
 /// This reader will expect json objects split by new lines.
+///
+/// Values are parsed from the stream rather than line by line, so a value spanning multiple lines
+/// (e.g. pretty printed objects) is returned as a single item.
 #[derive(Serialize, Deserialize)]
 pub struct JsonStreamReader {
     /// Path for the file to read
@@ -19,7 +25,7 @@ pub struct JsonStreamReader {
 
     /// Stream reader
     #[serde(skip)]
-    _iterator: Option<Arc<Mutex<dyn Iterator<Item = Result<Value, serde_json::Error>>>>>,
+    _iterator: Option<ValueStream>,
 
     /// Indicate if the reader has already been initialized
     #[serde(default)]
@@ -59,16 +65,16 @@ impl FileReader for JsonStreamReader {
     ///
     /// The JSON reader will not convert any type as it already read json from file
     fn read_item(&mut self) -> Option<Result<Value, ReaderError>> {
-        if self._iterator.is_none() {
-            if let Err(e) = self.init() {
-                self._initialized = true;
-                tracing::error!(
-                    "JsonStreamReader initialization error : {:?} - file path : {}",
-                    e,
-                    self.file_path
-                );
-                return Some(Err(e));
-            }
+        if self._iterator.is_none()
+            && let Err(e) = self.init()
+        {
+            self._initialized = true;
+            tracing::error!(
+                "JsonStreamReader initialization error : {:?} - file path : {}",
+                e,
+                self.file_path
+            );
+            return Some(Err(e));
         }
 
         let Some(iterator) = &self._iterator else {
@@ -82,10 +88,18 @@ impl FileReader for JsonStreamReader {
             Err(_) => Some(Err(ReaderError::InitializationError("Mutex lock poisoned"))),
         }
     }
+
+    /// Checks that the file exists and is readable.
+    fn preflight(&self) -> Result<(), ReaderError> {
+        check_readable(&self.file_path)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
     use super::*;
 
     fn get_file() -> String {
@@ -138,11 +152,38 @@ mod tests {
 
         assert_eq!(results[0]["name"].as_str().unwrap(), "My super product");
         assert_eq!(results[0]["price"].as_f64().unwrap(), 10.5);
-        assert_eq!(results[0]["inStock"].as_bool().unwrap(), true);
+        assert!(results[0]["inStock"].as_bool().unwrap());
 
         assert_eq!(results[1]["name"].as_str().unwrap(), "My other product");
         assert_eq!(results[1]["price"].as_f64().unwrap(), 20.0);
-        assert_eq!(results[1]["inStock"].as_bool().unwrap(), false);
+        assert!(!results[1]["inStock"].as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_json_stream_reader_multi_line_values() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            "{{\n  \"name\": \"My super product\",\n  \"tags\": [\n    \"new\"\n  ]\n}}"
+        )
+        .unwrap();
+        writeln!(file, "{{\"name\": \"My other product\", \"tags\": []}}").unwrap();
+
+        let mut reader = JsonStreamReader {
+            file_path: file.path().to_str().unwrap().to_string(),
+            _iterator: None,
+            _initialized: false,
+        };
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(results.len(), 2, "Expected 2 results");
+        assert_eq!(results[0]["tags"][0].as_str().unwrap(), "new");
+        assert_eq!(results[1]["name"].as_str().unwrap(), "My other product");
     }
 
     #[test]
@@ -176,4 +217,21 @@ mod tests {
         // Initialize the reader
         assert!(reader.init().is_err(), "init error expected");
     }
+
+    #[test]
+    fn test_json_stream_reader_preflight() {
+        let reader = JsonStreamReader {
+            file_path: get_file(),
+            _iterator: None,
+            _initialized: false,
+        };
+        assert!(reader.preflight().is_ok());
+
+        let reader = JsonStreamReader {
+            file_path: String::from("/invalid/file/path"),
+            _iterator: None,
+            _initialized: false,
+        };
+        assert!(reader.preflight().is_err(), "preflight error expected");
+    }
 }
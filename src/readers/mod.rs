@@ -1,12 +1,20 @@
 mod csv;
 mod errors;
 mod jsonstream;
+mod plugin;
+mod sniffer;
+mod swiftmt;
+
+use std::{fs::File, path::Path};
 
 use serde_json::Value;
 
 pub use csv::CsvReader;
 pub use errors::ReaderError;
 pub use jsonstream::JsonStreamReader;
+pub use plugin::PluginReader;
+pub use sniffer::{CsvDialect, LineTerminator, detect_dialect};
+pub use swiftmt::SwiftMtReader;
 
 /// Trait defining the functionalities of a file reader.
 ///
@@ -43,4 +51,27 @@ pub trait FileReader {
     /// }
     /// ```
     fn read_item(&mut self) -> Option<Result<Value, ReaderError>>;
+
+    /// Checks that the reader can be used, without consuming any item.
+    ///
+    /// This method verifies connectivity and permissions (e.g. the file exists and is readable), so problems can be
+    /// detected before scheduling the actual read. The default implementation performs no check.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ReaderError>` - Returns `Ok(())` if the reader is ready, or the error preventing it from reading.
+    fn preflight(&self) -> Result<(), ReaderError> {
+        Ok(())
+    }
+}
+
+/// Checks that `path` is a regular file that can be opened for reading.
+fn check_readable(path: &str) -> Result<(), ReaderError> {
+    if !Path::new(path).metadata()?.is_file() {
+        return Err(ReaderError::PreflightError(format!("{path} is not a file")));
+    }
+
+    File::open(path)?;
+
+    Ok(())
 }
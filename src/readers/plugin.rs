@@ -0,0 +1,356 @@
+use std::{
+    env,
+    io::{BufRead, BufReader, Lines, Write},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdout, Command, Stdio},
+    thread::{self, JoinHandle},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{FileReader, ReaderError};
+
+/// Prefix of the executables providing reader plugins.
+const PLUGIN_PREFIX: &str = "rustifile-reader-";
+
+/// Struct representing a reader delegating to an external plugin process.
+///
+/// Plugins allow shipping readers for proprietary formats as separate artifacts. A plugin named `foo`
+/// is an executable called `rustifile-reader-foo`, looked up in `plugin_dirs` then in the `PATH`.
+///
+/// The plugin protocol is newline delimited JSON over stdio:
+/// - the plugin is started with `args` and receives `options` as a single JSON document on its stdin,
+///   which is closed right after
+/// - the plugin writes one JSON value per line on its stdout, each line being returned as an item
+/// - a non zero exit status is reported as an error once all items have been read
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PluginReader {
+    /// Name of the plugin to run
+    name: String,
+
+    /// Arguments passed to the plugin executable
+    #[serde(default)]
+    args: Vec<String>,
+
+    /// Options sent to the plugin on its stdin
+    #[serde(default)]
+    options: Value,
+
+    /// Directories searched for the plugin executable before the `PATH`
+    #[serde(default)]
+    plugin_dirs: Vec<String>,
+
+    /// The running plugin process. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _child: Option<Child>,
+
+    /// Lines written by the plugin on its stdout. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _lines: Option<Lines<BufReader<ChildStdout>>>,
+
+    /// Thread sending the options to the plugin. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _stdin_writer: Option<JoinHandle<std::io::Result<()>>>,
+
+    /// Indicate if the reader has already been initialized
+    #[serde(default)]
+    _initialized: bool,
+}
+
+impl PluginReader {
+    /// Finds the plugin executable in `plugin_dirs`, then in the directories of the `PATH` environment variable.
+    fn find_plugin(&self) -> Result<PathBuf, ReaderError> {
+        let file_name = format!("{PLUGIN_PREFIX}{}", self.name);
+        let path_dirs: Vec<PathBuf> = env::var_os("PATH")
+            .map(|paths| env::split_paths(&paths).collect())
+            .unwrap_or_default();
+
+        self.plugin_dirs
+            .iter()
+            .map(PathBuf::from)
+            .chain(path_dirs)
+            .map(|dir| dir.join(&file_name))
+            .find(|path| is_executable(path))
+            .ok_or_else(|| ReaderError::PluginError(format!("plugin `{}` not found", self.name)))
+    }
+
+    /// Starts the plugin process and sends it its options.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ReaderError>` - Returns `Ok(())` if the plugin is started, or an error if it cannot be found or spawned.
+    fn init_plugin(&mut self) -> Result<(), ReaderError> {
+        let path = self.find_plugin()?;
+
+        let mut child = Command::new(&path)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        // Options are sent from another thread, as a plugin may write items before reading all of its
+        // options, which would block both processes on full pipes
+        if let Some(mut stdin) = child.stdin.take() {
+            let mut options = serde_json::to_vec(&self.options)?;
+            options.push(b'\n');
+            self._stdin_writer = Some(thread::spawn(move || {
+                match stdin.write_all(&options) {
+                    // A plugin ignoring its options may exit before reading them
+                    Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+                    result => result,
+                }
+            }));
+        }
+
+        self._lines = child
+            .stdout
+            .take()
+            .map(|stdout| BufReader::new(stdout).lines());
+        self._child = Some(child);
+
+        tracing::debug!("Started plugin {:?} with config : {:?}", path, self);
+
+        Ok(())
+    }
+
+    /// Waits for the plugin process to exit, returning an error if it did not succeed.
+    fn wait_plugin(&mut self) -> Option<Result<Value, ReaderError>> {
+        let mut child = self._child.take()?;
+        self._lines = None;
+
+        if let Some(Ok(Err(e))) = self._stdin_writer.take().map(JoinHandle::join) {
+            let _ = child.wait();
+            return Some(Err(e.into()));
+        }
+
+        match child.wait() {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(Err(ReaderError::PluginError(format!(
+                "plugin `{}` exited with {}",
+                self.name, status
+            )))),
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+/// Stops the plugin process if the reader is dropped before the plugin is done, so it is not left as a zombie.
+impl Drop for PluginReader {
+    fn drop(&mut self) {
+        if let Some(mut child) = self._child.take() {
+            if let Err(e) = child.kill() {
+                tracing::warn!("Cannot kill plugin `{}` : {:?}", self.name, e);
+            }
+            if let Err(e) = child.wait() {
+                tracing::warn!("Cannot wait for plugin `{}` : {:?}", self.name, e);
+            }
+        }
+        // The plugin is stopped, so the options thread cannot be blocked on its stdin anymore
+        if let Some(writer) = self._stdin_writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Returns true if the path is a file that can be executed.
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// Implementation of the `FileReader` trait for `PluginReader`.
+///
+/// This implementation allows plugins to be used as any other file reader.
+#[typetag::serde(name = "plugin")]
+impl FileReader for PluginReader {
+    /// Reads an item from the plugin output.
+    ///
+    /// This method is called iteratively to return a `serde_json::Value` for each line written by the plugin.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Result<Value, ReaderError>>` - Returns `Some(Ok(Value))` if an item is found, `Some(Err(ReaderError))` if an error is encountered, or `None` if the plugin is done.
+    /// # Type Conversion
+    ///
+    /// The plugin reader will not convert any type as plugins already write json
+    fn read_item(&mut self) -> Option<Result<Value, ReaderError>> {
+        if !self._initialized {
+            self._initialized = true;
+            if let Err(e) = self.init_plugin() {
+                tracing::error!(
+                    "PluginReader initialization error : {:?} - Config : {:?}",
+                    e,
+                    self
+                );
+                return Some(Err(e));
+            }
+        }
+
+        let lines = self._lines.as_mut()?;
+        loop {
+            match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => continue,
+                Some(Ok(line)) => return Some(serde_json::from_str(&line).map_err(|e| e.into())),
+                Some(Err(e)) => return Some(Err(e.into())),
+                None => return self.wait_plugin(),
+            }
+        }
+    }
+
+    /// Checks that the plugin executable can be found.
+    fn preflight(&self) -> Result<(), ReaderError> {
+        self.find_plugin().map(|_| ())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{os::unix::fs::PermissionsExt, sync::OnceLock};
+
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Creates the test plugins once, so no plugin file is being written while another test spawns a process.
+    fn plugin_dir() -> String {
+        static DIR: OnceLock<TempDir> = OnceLock::new();
+
+        let dir = DIR.get_or_init(|| {
+            let dir = TempDir::new().unwrap();
+            let plugins = [
+                ("echo", "#!/bin/sh\ncat\n"),
+                (
+                    "args",
+                    "#!/bin/sh\nfor arg in \"$@\"; do echo \"{\\\"arg\\\": \\\"$arg\\\"}\"; done\n",
+                ),
+                (
+                    "failing",
+                    "#!/bin/sh\necho '{\"id\": 1}'\necho 'not json'\nexit 3\n",
+                ),
+                ("many", "#!/bin/sh\nexec yes '{\"id\": 1}'\n"),
+            ];
+            for (name, script) in plugins {
+                let path = dir.path().join(format!("{PLUGIN_PREFIX}{name}"));
+                std::fs::write(&path, script).unwrap();
+                std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            }
+            dir
+        });
+
+        dir.path().to_str().unwrap().to_string()
+    }
+
+    fn plugin_reader(name: &str, args: Vec<String>, options: Value) -> PluginReader {
+        PluginReader {
+            name: name.to_string(),
+            args,
+            options,
+            plugin_dirs: vec![plugin_dir()],
+            _child: None,
+            _lines: None,
+            _stdin_writer: None,
+            _initialized: false,
+        }
+    }
+
+    #[test]
+    fn test_plugin_receives_options() {
+        let mut reader = plugin_reader("echo", vec![], json!({"path": "/tmp/file.dat"}));
+
+        let item = reader.read_item().unwrap().unwrap();
+        assert_eq!(item, json!({"path": "/tmp/file.dat"}));
+        assert!(reader.read_item().is_none());
+    }
+
+    #[test]
+    fn test_plugin_receives_large_options() {
+        // Larger than pipe buffers, so the plugin writes its output while still reading its options
+        let options = json!({"data": "x".repeat(1024 * 1024)});
+        let mut reader = plugin_reader("echo", vec![], options.clone());
+
+        assert_eq!(reader.read_item().unwrap().unwrap(), options);
+        assert!(reader.read_item().is_none());
+    }
+
+    #[test]
+    fn test_plugin_receives_args() {
+        let args = vec!["first".to_string(), "second".to_string()];
+        let mut reader = plugin_reader("args", args, Value::Null);
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let valid_results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(
+            valid_results,
+            vec![json!({"arg": "first"}), json!({"arg": "second"})]
+        );
+    }
+
+    #[test]
+    fn test_plugin_failure() {
+        let mut reader = plugin_reader("failing", vec![], Value::Null);
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &json!({"id": 1}));
+        assert!(matches!(results[1], Err(ReaderError::JsonError(_))));
+        assert!(matches!(results[2], Err(ReaderError::PluginError(_))));
+    }
+
+    #[test]
+    fn test_plugin_preflight() {
+        assert!(
+            plugin_reader("echo", vec![], Value::Null)
+                .preflight()
+                .is_ok()
+        );
+        assert!(matches!(
+            plugin_reader("missing", vec![], Value::Null).preflight(),
+            Err(ReaderError::PluginError(_))
+        ));
+    }
+
+    #[test]
+    fn test_plugin_not_found() {
+        let mut reader = plugin_reader("missing", vec![], Value::Null);
+
+        assert!(matches!(
+            reader.read_item(),
+            Some(Err(ReaderError::PluginError(_)))
+        ));
+        assert!(reader.read_item().is_none(), "Expected None after error");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_dropped_plugin_is_reaped() {
+        let mut reader = plugin_reader("many", vec![], Value::Null);
+
+        assert_eq!(reader.read_item().unwrap().unwrap(), json!({"id": 1}));
+        let pid = reader._child.as_ref().unwrap().id();
+        drop(reader);
+
+        assert!(
+            !Path::new(&format!("/proc/{pid}")).exists(),
+            "Plugin process {pid} was not reaped"
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use std::{fs::File, io::Read, path::Path};
+
+use super::ReaderError;
+
+/// Maximum number of bytes sampled from the file to infer its dialect.
+const SAMPLE_SIZE: u64 = 64 * 1024;
+
+/// Delimiters tried by the sniffer, in order of preference when several of them fit equally well.
+const DELIMITER_CANDIDATES: [u8; 5] = [b',', b';', b'\t', b'|', b':'];
+
+/// Quote characters tried by the sniffer, in order of preference.
+const QUOTE_CANDIDATES: [u8; 2] = [b'"', b'\''];
+
+/// Line terminator detected in a CSV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineTerminator {
+    /// Unix style line endings (`\n`)
+    Lf,
+    /// Windows style line endings (`\r\n`)
+    CrLf,
+    /// Classic Mac OS line endings (`\r`)
+    Cr,
+}
+
+/// Struct describing the dialect of a CSV file, as inferred by [`detect_dialect`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// The field delimiter
+    pub delimiter: u8,
+
+    /// The quote character
+    pub quote: u8,
+
+    /// Whether the first row looks like a header row
+    pub has_headers: bool,
+
+    /// The line terminator
+    pub terminator: LineTerminator,
+}
+
+/// Infers the dialect of the CSV file located at `path`.
+///
+/// This function samples the beginning of the file and guesses its delimiter, quote character,
+/// header presence and line terminator, the same way Python's `csv.Sniffer` does.
+///
+/// The delimiter is the candidate (`,`, `;`, tab, `|` or `:`) that splits the most sampled rows
+/// into the same number of fields. Files where no candidate splits rows are considered single column
+/// files and fall back to a comma. Headers are assumed present unless the first row looks like data.
+///
+/// # Returns
+///
+/// * `Result<CsvDialect, ReaderError>` - Returns the inferred dialect, or an error if the file cannot be read or is empty.
+pub fn detect_dialect<P: AsRef<Path>>(path: P) -> Result<CsvDialect, ReaderError> {
+    let mut bytes = vec![];
+    File::open(path)?
+        .take(SAMPLE_SIZE + 1)
+        .read_to_end(&mut bytes)?;
+
+    // Drop the last line if the sample was cut in the middle of the file
+    if bytes.len() as u64 > SAMPLE_SIZE {
+        bytes.truncate(SAMPLE_SIZE as usize);
+        if let Some(pos) = bytes.iter().rposition(|b| *b == b'\n' || *b == b'\r') {
+            bytes.truncate(pos + 1);
+        }
+    }
+
+    let sample = String::from_utf8_lossy(&bytes);
+    if sample.trim().is_empty() {
+        return Err(ReaderError::DialectError("file is empty"));
+    }
+
+    let terminator = detect_terminator(&sample);
+    let quote = detect_quote(&sample);
+    let delimiter = detect_delimiter(&sample, quote);
+    let has_headers = detect_headers(&sample, delimiter, quote);
+
+    let dialect = CsvDialect {
+        delimiter,
+        quote,
+        has_headers,
+        terminator,
+    };
+
+    tracing::debug!("Detected csv dialect : {:?}", dialect);
+
+    Ok(dialect)
+}
+
+/// Returns the terminator of the first line of the sample, defaulting to `\n`.
+fn detect_terminator(sample: &str) -> LineTerminator {
+    match sample.find(['\r', '\n']) {
+        Some(pos) if sample[pos..].starts_with("\r\n") => LineTerminator::CrLf,
+        Some(pos) if sample[pos..].starts_with('\r') => LineTerminator::Cr,
+        _ => LineTerminator::Lf,
+    }
+}
+
+/// Returns the quote candidate opening the most fields, defaulting to `"`.
+///
+/// A quote opens a field when it is the first character of a line or directly follows a delimiter candidate.
+fn detect_quote(sample: &str) -> u8 {
+    let bytes = sample.as_bytes();
+    let mut best = (QUOTE_CANDIDATES[0], 0);
+
+    for quote in QUOTE_CANDIDATES {
+        let count = bytes
+            .iter()
+            .enumerate()
+            .filter(|(i, b)| {
+                **b == quote
+                    && (*i == 0
+                        || matches!(bytes[i - 1], b'\n' | b'\r')
+                        || DELIMITER_CANDIDATES.contains(&bytes[i - 1]))
+            })
+            .count();
+
+        if count > best.1 {
+            best = (quote, count);
+        }
+    }
+
+    best.0
+}
+
+/// Parses the sample with the given delimiter and quote, returning the fields of each row.
+fn parse_sample(sample: &str, delimiter: u8, quote: u8) -> Vec<csv::StringRecord> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote)
+        .from_reader(sample.as_bytes())
+        .records()
+        .map_while(Result::ok)
+        .collect()
+}
+
+/// Returns the delimiter candidate splitting the most rows into a consistent number of fields.
+fn detect_delimiter(sample: &str, quote: u8) -> u8 {
+    let mut best = (b',', 0);
+
+    for delimiter in DELIMITER_CANDIDATES {
+        let records = parse_sample(sample, delimiter, quote);
+
+        // Most frequent field count across the rows, ignoring rows that were not split
+        let mut counts: Vec<(usize, usize)> = vec![];
+        for len in records.iter().map(|r| r.len()).filter(|len| *len > 1) {
+            match counts.iter_mut().find(|(l, _)| *l == len) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((len, 1)),
+            }
+        }
+
+        let score = counts.iter().map(|(_, n)| *n).max().unwrap_or(0);
+        if score > best.1 {
+            best = (delimiter, score);
+        }
+    }
+
+    best.0
+}
+
+/// Type of a cell value, used to compare the first row with the rest of the sample.
+#[derive(Debug, PartialEq)]
+enum CellKind {
+    Number,
+    Bool,
+    Text(usize),
+}
+
+impl CellKind {
+    fn of(value: &str) -> Self {
+        let value = value.trim();
+        if value.parse::<f64>().is_ok() {
+            CellKind::Number
+        } else if value == "true" || value == "false" {
+            CellKind::Bool
+        } else {
+            CellKind::Text(value.chars().count())
+        }
+    }
+}
+
+/// Guesses whether the first row of the sample is a header row.
+///
+/// Each column whose values share the same kind (number, boolean or fixed length text) votes for a header
+/// if the first row cell has a different kind, and against otherwise. Headers are assumed when votes are even.
+fn detect_headers(sample: &str, delimiter: u8, quote: u8) -> bool {
+    let records = parse_sample(sample, delimiter, quote);
+    let Some((header, rows)) = records.split_first() else {
+        return true;
+    };
+
+    let mut votes = 0;
+    for (col, cell) in header.iter().enumerate() {
+        let mut kinds = rows
+            .iter()
+            .filter_map(|r| r.get(col))
+            .filter(|v| !v.trim().is_empty())
+            .map(CellKind::of);
+
+        let Some(kind) = kinds.next() else {
+            continue;
+        };
+        if kinds.any(|k| k != kind) {
+            continue;
+        }
+
+        if CellKind::of(cell) == kind {
+            votes -= 1;
+        } else {
+            votes += 1;
+        }
+    }
+
+    votes >= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    fn write_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_detect_example_file() {
+        let dialect =
+            detect_dialect(format!("{}/examples/uspop.csv", env!("CARGO_MANIFEST_DIR"))).unwrap();
+
+        assert_eq!(
+            dialect,
+            CsvDialect {
+                delimiter: b',',
+                quote: b'"',
+                has_headers: true,
+                terminator: LineTerminator::Lf,
+            }
+        );
+    }
+
+    #[test]
+    fn test_detect_semicolon_and_crlf() {
+        let file = write_file("name;price;city\r\nfoo;1,5;\"Paris; France\"\r\nbar;2,0;Lyon\r\n");
+        let dialect = detect_dialect(file.path()).unwrap();
+
+        assert_eq!(dialect.delimiter, b';');
+        assert_eq!(dialect.terminator, LineTerminator::CrLf);
+        assert!(dialect.has_headers);
+    }
+
+    #[test]
+    fn test_detect_single_quote_and_tab() {
+        let file = write_file("'New York'\tNY\t8419000\n'Los Angeles, CA'\tCA\t3971000\n");
+        let dialect = detect_dialect(file.path()).unwrap();
+
+        assert_eq!(dialect.delimiter, b'\t');
+        assert_eq!(dialect.quote, b'\'');
+    }
+
+    #[test]
+    fn test_detect_no_headers() {
+        let file = write_file("1|foo|true\n2|bar|false\n3|baz|true\n");
+        let dialect = detect_dialect(file.path()).unwrap();
+
+        assert_eq!(dialect.delimiter, b'|');
+        assert!(!dialect.has_headers);
+    }
+
+    #[test]
+    fn test_detect_empty_file() {
+        let file = write_file("");
+
+        assert!(matches!(
+            detect_dialect(file.path()),
+            Err(ReaderError::DialectError(_))
+        ));
+    }
+}
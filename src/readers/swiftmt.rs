@@ -0,0 +1,354 @@
+use std::{
+    fs::File,
+    io::{BufReader, Bytes, Read},
+    sync::LazyLock,
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use super::{FileReader, ReaderError, check_readable};
+
+/// Matches the tags starting the fields of the text block (`:20:`, `:32A:`...).
+static FIELD_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^:(\d{2}[A-Z]?):").expect("valid field tag regex"));
+
+/// Matches the sub blocks of the user header and trailer blocks (`{108:MT103 001}`).
+static SUB_BLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{([^:{}]+):([^{}]*)\}").expect("valid sub block regex"));
+
+/// Struct representing a SWIFT MT message reader.
+///
+/// This reader parses files of SWIFT MT messages (MT103, MT940...) and returns one item per message.
+/// Messages start with their basic header block (`{1:...}`), anything between blocks (new lines, `$`) is ignored.
+///
+/// Each item contains:
+/// - `basic_header` and `application_header`: raw content of blocks 1 and 2
+/// - `message_type`: the message type read from the application header (`"103"`, `"940"`...)
+/// - `user_header` and `trailer`: blocks 3 and 5 as objects of their sub blocks
+/// - `fields`: the text block fields as a list of `{"tag": ..., "value": ...}`, as tags can be repeated.
+///   Multi-line values are joined with `\n`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SwiftMtReader {
+    /// Path for the file to read
+    file_path: String,
+
+    /// Bytes of the file. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _bytes: Option<Bytes<BufReader<File>>>,
+
+    /// Basic header block of the next message, read while looking for the end of the previous one
+    #[serde(skip)]
+    _pending: Option<(String, String)>,
+
+    /// Indicate if the reader has already been initialized
+    #[serde(default)]
+    _initialized: bool,
+}
+
+impl SwiftMtReader {
+    /// Initializes the SWIFT MT reader by opening the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ReaderError>` - Returns `Ok(())` if the reader is successfully initialized, or an error if the file cannot be opened.
+    fn init_reader(&mut self) -> Result<(), ReaderError> {
+        self._bytes = Some(BufReader::new(File::open(&self.file_path)?).bytes());
+
+        tracing::debug!("Initialized swift mt reader with config : {:?}", self);
+
+        Ok(())
+    }
+
+    /// Reads the next top level block of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Result<(String, String), ReaderError>>` - Returns the block identifier and content,
+    ///   `None` if the file is exhausted, or an error if the block is not terminated or has no identifier.
+    fn next_block(&mut self) -> Option<Result<(String, String), ReaderError>> {
+        let bytes = self._bytes.as_mut()?;
+        let mut depth = 0;
+        let mut block = vec![];
+
+        for byte in bytes {
+            let byte = match byte {
+                Ok(byte) => byte,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            match (byte, depth) {
+                (b'{', 0) => depth = 1,
+                (_, 0) => continue,
+                (b'}', 1) => {
+                    let block = String::from_utf8_lossy(&block);
+                    return Some(
+                        block
+                            .split_once(':')
+                            .map(|(id, content)| (id.to_string(), content.to_string()))
+                            .ok_or(ReaderError::SwiftError("block without identifier")),
+                    );
+                }
+                (b'{', _) => {
+                    depth += 1;
+                    block.push(byte);
+                }
+                (b'}', _) => {
+                    depth -= 1;
+                    block.push(byte);
+                }
+                _ => block.push(byte),
+            }
+        }
+
+        if depth > 0 {
+            Some(Err(ReaderError::SwiftError("unterminated block")))
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses sub blocks (`{108:MT103 001}{121:...}`) into an object.
+fn parse_sub_blocks(content: &str) -> Value {
+    Value::Object(
+        SUB_BLOCK
+            .captures_iter(content)
+            .map(|c| (c[1].to_string(), Value::String(c[2].to_string())))
+            .collect(),
+    )
+}
+
+/// Parses the text block into a list of tagged fields.
+fn parse_fields(content: &str) -> Value {
+    let content = content.replace("\r\n", "\n");
+    let content = content.trim_end();
+    let content = content.strip_suffix('-').unwrap_or(content);
+
+    let tags: Vec<_> = FIELD_TAG.captures_iter(content).collect();
+    let fields = tags
+        .iter()
+        .enumerate()
+        .map(|(i, captures)| {
+            let start = captures.get(0).map_or(0, |m| m.end());
+            let end = tags
+                .get(i + 1)
+                .and_then(|next| next.get(0))
+                .map_or(content.len(), |m| m.start());
+
+            json!({
+                "tag": &captures[1],
+                "value": content[start..end].trim_end_matches('\n'),
+            })
+        })
+        .collect();
+
+    Value::Array(fields)
+}
+
+/// Builds the item of a message from its blocks.
+fn parse_message(blocks: Vec<(String, String)>) -> Value {
+    let mut message = Map::new();
+    message.insert("message_type".to_string(), Value::Null);
+
+    for (id, content) in blocks {
+        match id.as_str() {
+            "1" => {
+                message.insert("basic_header".to_string(), Value::String(content));
+            }
+            "2" => {
+                if let Some(message_type) = content.get(1..4) {
+                    message.insert(
+                        "message_type".to_string(),
+                        Value::String(message_type.to_string()),
+                    );
+                }
+                message.insert("application_header".to_string(), Value::String(content));
+            }
+            "3" => {
+                message.insert("user_header".to_string(), parse_sub_blocks(&content));
+            }
+            "4" => {
+                message.insert("fields".to_string(), parse_fields(&content));
+            }
+            "5" => {
+                message.insert("trailer".to_string(), parse_sub_blocks(&content));
+            }
+            _ => {
+                message.insert(format!("block_{id}"), Value::String(content));
+            }
+        }
+    }
+
+    Value::Object(message)
+}
+
+/// Implementation of the `FileReader` trait for `SwiftMtReader`.
+///
+/// This implementation allows `SwiftMtReader` to be used as a file reader that iterates over messages.
+#[typetag::serde(name = "swiftmt")]
+impl FileReader for SwiftMtReader {
+    /// Reads a message from the file.
+    ///
+    /// This method is called iteratively to return a `serde_json::Value` for each message present in the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Result<Value, ReaderError>>` - Returns `Some(Ok(Value))` if a message is found, `Some(Err(ReaderError))` if an error is encountered, or `None` if the file is exhausted.
+    /// # Type Conversion
+    ///
+    /// The SWIFT MT reader will not convert any type, all values are returned as JSON Strings
+    fn read_item(&mut self) -> Option<Result<Value, ReaderError>> {
+        if !self._initialized {
+            self._initialized = true;
+            if let Err(e) = self.init_reader() {
+                tracing::error!(
+                    "SwiftMtReader initialization error : {:?} - Config : {:?}",
+                    e,
+                    self
+                );
+                return Some(Err(e));
+            }
+        }
+
+        let mut blocks: Vec<(String, String)> = self._pending.take().into_iter().collect();
+        loop {
+            match self.next_block() {
+                Some(Ok(block)) if block.0 == "1" && !blocks.is_empty() => {
+                    self._pending = Some(block);
+                    break;
+                }
+                Some(Ok(block)) => blocks.push(block),
+                Some(Err(e)) => {
+                    self._bytes = None;
+                    return Some(Err(e));
+                }
+                None => break,
+            }
+        }
+
+        if blocks.is_empty() {
+            return None;
+        }
+
+        Some(Ok(parse_message(blocks)))
+    }
+
+    /// Checks that the file exists and is readable.
+    fn preflight(&self) -> Result<(), ReaderError> {
+        check_readable(&self.file_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    fn get_reader(file_path: String) -> SwiftMtReader {
+        SwiftMtReader {
+            file_path,
+            _bytes: None,
+            _pending: None,
+            _initialized: false,
+        }
+    }
+
+    #[test]
+    fn test_reading_file() {
+        let mut reader = get_reader(format!(
+            "{}/examples/swift_mt.txt",
+            env!("CARGO_MANIFEST_DIR")
+        ));
+
+        let mut results: Vec<Result<Value, ReaderError>> = vec![];
+        while let Some(item) = reader.read_item() {
+            results.push(item);
+        }
+
+        let valid_results: Vec<Value> = results.into_iter().flatten().collect();
+        assert_eq!(valid_results.len(), 2);
+
+        let mt103 = &valid_results[0];
+        assert_eq!(mt103["message_type"], json!("103"));
+        assert_eq!(mt103["basic_header"], json!("F01BANKBEBBAXXX0000000000"));
+        assert_eq!(mt103["user_header"]["108"], json!("MT103 001"));
+        assert_eq!(mt103["trailer"], json!({"CHK": "1A2B3C4D5E6F"}));
+        assert_eq!(
+            mt103["fields"][0],
+            json!({"tag": "20", "value": "REF-2024-0001"})
+        );
+        assert_eq!(
+            mt103["fields"][3],
+            json!({"tag": "50K", "value": "/BE68539007547034\nJOHN DOE\nRUE DE LA LOI 1"})
+        );
+        assert_eq!(mt103["fields"][5], json!({"tag": "71A", "value": "SHA"}));
+
+        let mt940 = &valid_results[1];
+        assert_eq!(mt940["message_type"], json!("940"));
+        assert!(mt940.get("user_header").is_none());
+        let tags: Vec<&str> = mt940["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["tag"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            tags,
+            vec!["20", "25", "28C", "60F", "61", "86", "61", "86", "62F"]
+        );
+    }
+
+    #[test]
+    fn test_crlf_and_separators() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            "{{1:F01BANKBEBBAXXX0000000000}}{{2:I103BANKDEFFXXXXN}}{{4:\r\n:20:FIRST\r\n:70:LINE 1\r\nLINE 2\r\n-}}$\
+             {{1:F01BANKBEBBAXXX0000000000}}{{2:I202BANKDEFFXXXXN}}{{4:\r\n:20:SECOND\r\n-}}"
+        )
+        .unwrap();
+
+        let mut reader = get_reader(file.path().to_str().unwrap().to_string());
+
+        let first = reader.read_item().unwrap().unwrap();
+        assert_eq!(
+            first["fields"][1],
+            json!({"tag": "70", "value": "LINE 1\nLINE 2"})
+        );
+
+        let second = reader.read_item().unwrap().unwrap();
+        assert_eq!(second["message_type"], json!("202"));
+        assert_eq!(second["fields"], json!([{"tag": "20", "value": "SECOND"}]));
+
+        assert!(reader.read_item().is_none());
+    }
+
+    #[test]
+    fn test_unterminated_block() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{{1:F01BANKBEBBAXXX0000000000}}{{4:\n:20:REF\n").unwrap();
+
+        let mut reader = get_reader(file.path().to_str().unwrap().to_string());
+
+        assert!(matches!(
+            reader.read_item(),
+            Some(Err(ReaderError::SwiftError(_)))
+        ));
+        assert!(reader.read_item().is_none(), "Expected None after error");
+    }
+
+    #[test]
+    fn test_nonexistent_file() {
+        let mut reader = get_reader("nonexistent_file.txt".to_string());
+
+        assert!(matches!(
+            reader.read_item(),
+            Some(Err(ReaderError::IoError(_)))
+        ));
+        assert!(reader.read_item().is_none(), "Expected None after error");
+    }
+}
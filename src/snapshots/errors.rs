@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+use crate::readers::ReaderError;
+
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+    #[error(transparent)]
+    ReaderError(#[from] ReaderError),
+    #[error("Snapshot not found: {0}")]
+    NotFound(String),
+    #[error("Snapshot checksum mismatch: {0}")]
+    ChecksumMismatch(String),
+}
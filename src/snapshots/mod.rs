@@ -0,0 +1,569 @@
+mod errors;
+mod sha256;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::readers::FileReader;
+pub use errors::SnapshotError;
+use sha256::Sha256;
+
+/// Name of the data file of a snapshot.
+const DATA_FILE: &str = "data.jsonl";
+
+/// Name of the manifest file of a snapshot.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Prefix of the directories snapshots are written to before being committed.
+const TMP_PREFIX: &str = ".tmp-";
+
+/// Directory holding a `v<N>` marker file for each allocated version number.
+const VERSIONS_DIR: &str = "versions";
+
+/// Counter keeping temporary directory names unique between threads of the same process.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Struct describing a snapshot, stored as `manifest.json` next to its data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Content address of the snapshot, derived from its data checksum and its parent
+    pub version_id: String,
+
+    /// Sequence number of the snapshot in its store, starting at 1. Numbers are unique and increasing, but the
+    /// numbers of removed snapshots are not reused, and a number may be skipped when concurrent writers store the
+    /// same records.
+    pub version: u64,
+
+    /// Version id of the snapshot this one was derived from
+    pub parent: Option<String>,
+
+    /// Creation time, in seconds since the Unix epoch
+    pub created_at: u64,
+
+    /// Number of records in the snapshot
+    pub record_count: u64,
+
+    /// JSON types seen for each top level field of the records
+    pub schema: BTreeMap<String, BTreeSet<String>>,
+
+    /// SHA-256 checksum of the data file
+    pub checksum: String,
+}
+
+/// Returns the name of the JSON type of a value, as stored in snapshot schemas.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "float",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Struct representing a directory of versioned dataset snapshots.
+///
+/// Each snapshot is written under a content-addressed directory named after its version id, holding the records
+/// as newline delimited JSON (`data.jsonl`) and a manifest (`manifest.json`) with the schema, record count,
+/// checksum and parent version, giving reproducible dataset lineage.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustifile::snapshots::SnapshotStore;
+/// use serde_json::json;
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let store = SnapshotStore::new(dir.path());
+///
+/// let first = store.write(vec![json!({"id": 1})], None).unwrap();
+/// let second = store
+///     .write(vec![json!({"id": 1}), json!({"id": 2})], Some(&first.version_id))
+///     .unwrap();
+///
+/// assert_eq!(store.list_versions().unwrap(), vec![first, second.clone()]);
+///
+/// let mut reader = store.reader(&second.version_id).unwrap();
+/// assert_eq!(reader.read_item().unwrap().unwrap(), json!({"id": 1}));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    /// Directory holding the snapshots
+    root: PathBuf,
+}
+
+impl SnapshotStore {
+    /// Creates a store in the `root` directory. The directory is created on the first write.
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        SnapshotStore {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Writes records as a new snapshot.
+    ///
+    /// Records are written to a temporary directory, then moved to their content-addressed directory, so a
+    /// snapshot is either complete or absent. Writing the same records with the same parent returns the
+    /// existing snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SnapshotManifest, SnapshotError>` - Returns the manifest of the snapshot, or an error if the parent
+    ///   does not exist or the snapshot cannot be written.
+    pub fn write<I>(
+        &self,
+        records: I,
+        parent: Option<&str>,
+    ) -> Result<SnapshotManifest, SnapshotError>
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        if let Some(parent) = parent {
+            self.manifest(parent)?;
+        }
+
+        fs::create_dir_all(&self.root)?;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let tmp_dir = self.root.join(format!(
+            "{TMP_PREFIX}{}-{nanos}-{}",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir(&tmp_dir)?;
+
+        let result = self.write_tmp(&tmp_dir, records, parent);
+        if result.is_err() {
+            let _ = fs::remove_dir_all(&tmp_dir);
+        }
+        result
+    }
+
+    /// Writes a snapshot in a temporary directory and commits it.
+    fn write_tmp<I>(
+        &self,
+        tmp_dir: &Path,
+        records: I,
+        parent: Option<&str>,
+    ) -> Result<SnapshotManifest, SnapshotError>
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let mut writer = BufWriter::new(File::create(tmp_dir.join(DATA_FILE))?);
+        let mut hasher = Sha256::new();
+        let mut schema: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut record_count = 0;
+
+        for record in records {
+            if let Value::Object(fields) = &record {
+                for (field, value) in fields {
+                    schema
+                        .entry(field.clone())
+                        .or_default()
+                        .insert(type_name(value).to_string());
+                }
+            }
+
+            let mut line = serde_json::to_vec(&record)?;
+            line.push(b'\n');
+            hasher.update(&line);
+            writer.write_all(&line)?;
+            record_count += 1;
+        }
+        writer.flush()?;
+
+        let checksum = hasher.finalize_hex();
+        let mut id_hasher = Sha256::new();
+        id_hasher.update(format!("{}\n{}", parent.unwrap_or_default(), checksum).as_bytes());
+        let version_id = id_hasher.finalize_hex();
+
+        let version_dir = self.root.join(&version_id);
+        if version_dir.exists() {
+            fs::remove_dir_all(tmp_dir)?;
+            return self.manifest(&version_id);
+        }
+
+        let manifest = SnapshotManifest {
+            version: self.allocate_version(&version_id)?,
+            version_id,
+            parent: parent.map(str::to_string),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            record_count,
+            schema,
+            checksum,
+        };
+
+        self.commit(tmp_dir, manifest)
+    }
+
+    /// Allocates the next version number.
+    ///
+    /// Numbers are claimed by creating a `v<N>` marker file, which only one writer can create, so concurrent
+    /// writers never share a number. Claiming starts after the highest listed snapshot, in case markers were removed.
+    fn allocate_version(&self, version_id: &str) -> Result<u64, SnapshotError> {
+        let versions_dir = self.root.join(VERSIONS_DIR);
+        fs::create_dir_all(&versions_dir)?;
+
+        let mut version = self.list_versions()?.last().map_or(1, |m| m.version + 1);
+        loop {
+            match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(versions_dir.join(format!("v{version}")))
+            {
+                Ok(mut marker) => {
+                    marker.write_all(version_id.as_bytes())?;
+                    return Ok(version);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => version += 1,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Writes the manifest in the temporary directory and moves it to the snapshot directory.
+    ///
+    /// If a concurrent writer committed the same snapshot first, the temporary directory is removed and the
+    /// existing snapshot is returned.
+    fn commit(
+        &self,
+        tmp_dir: &Path,
+        manifest: SnapshotManifest,
+    ) -> Result<SnapshotManifest, SnapshotError> {
+        fs::write(
+            tmp_dir.join(MANIFEST_FILE),
+            serde_json::to_vec_pretty(&manifest)?,
+        )?;
+
+        let version_dir = self.root.join(&manifest.version_id);
+        if let Err(e) = fs::rename(tmp_dir, &version_dir) {
+            if matches!(
+                e.kind(),
+                ErrorKind::DirectoryNotEmpty | ErrorKind::AlreadyExists
+            ) && version_dir.exists()
+            {
+                fs::remove_dir_all(tmp_dir)?;
+                return self.manifest(&manifest.version_id);
+            }
+            return Err(e.into());
+        }
+
+        tracing::debug!("Written snapshot : {:?}", manifest);
+
+        Ok(manifest)
+    }
+
+    /// Returns the manifest of a snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SnapshotManifest, SnapshotError>` - Returns the manifest, or a `NotFound` error if the snapshot does not exist.
+    pub fn manifest(&self, version_id: &str) -> Result<SnapshotManifest, SnapshotError> {
+        // Version ids are hex digests, anything else cannot address a snapshot directory
+        let path = self.root.join(version_id).join(MANIFEST_FILE);
+        if version_id.is_empty()
+            || !version_id.chars().all(|c| c.is_ascii_hexdigit())
+            || !path.is_file()
+        {
+            return Err(SnapshotError::NotFound(version_id.to_string()));
+        }
+
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+
+    /// Lists the snapshots of the store, ordered by version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<SnapshotManifest>, SnapshotError>` - Returns the manifests, or an error if the store cannot be read.
+    pub fn list_versions(&self) -> Result<Vec<SnapshotManifest>, SnapshotError> {
+        if !self.root.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut manifests = vec![];
+        for entry in fs::read_dir(&self.root)? {
+            let name = entry?.file_name();
+            match self.manifest(&name.to_string_lossy()) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(SnapshotError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        manifests.sort_by_key(|m| m.version);
+
+        Ok(manifests)
+    }
+
+    /// Checks that the data of a snapshot matches the checksum of its manifest.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), SnapshotError>` - Returns `Ok(())` if the data is intact, or a `ChecksumMismatch` error otherwise.
+    pub fn verify(&self, version_id: &str) -> Result<(), SnapshotError> {
+        let manifest = self.manifest(version_id)?;
+
+        let mut file = BufReader::new(File::open(self.root.join(version_id).join(DATA_FILE))?);
+        let mut hasher = Sha256::new();
+        let mut buffer = [0; 8192];
+        loop {
+            let len = file.read(&mut buffer)?;
+            if len == 0 {
+                break;
+            }
+            hasher.update(&buffer[..len]);
+        }
+
+        if hasher.finalize_hex() != manifest.checksum {
+            return Err(SnapshotError::ChecksumMismatch(version_id.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reader over the records of a snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Box<dyn FileReader>, SnapshotError>` - Returns a json stream reader on the snapshot data, or a
+    ///   `NotFound` error if the snapshot does not exist.
+    pub fn reader(&self, version_id: &str) -> Result<Box<dyn FileReader>, SnapshotError> {
+        self.manifest(version_id)?;
+
+        let data_path = self.root.join(version_id).join(DATA_FILE);
+        Ok(serde_json::from_value(json!({
+            "type": "jsonstream",
+            "file_path": data_path.to_string_lossy(),
+        }))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_write_and_read_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path().join("snapshots"));
+
+        let manifest = store
+            .write(
+                vec![
+                    json!({"id": 1, "price": 10.5, "name": "chair"}),
+                    json!({"id": 2, "price": 20, "name": null}),
+                ],
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(manifest.version, 1);
+        assert_eq!(manifest.record_count, 2);
+        assert_eq!(manifest.parent, None);
+        assert_eq!(
+            manifest.schema["price"],
+            BTreeSet::from(["float".to_string(), "integer".to_string()])
+        );
+        assert_eq!(
+            manifest.schema["name"],
+            BTreeSet::from(["null".to_string(), "string".to_string()])
+        );
+        assert!(store.verify(&manifest.version_id).is_ok());
+
+        let mut reader = store.reader(&manifest.version_id).unwrap();
+        let mut records = vec![];
+        while let Some(item) = reader.read_item() {
+            records.push(item.unwrap());
+        }
+        assert_eq!(records[1], json!({"id": 2, "price": 20, "name": null}));
+    }
+
+    #[test]
+    fn test_versions_and_lineage() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+
+        let first = store.write(vec![json!({"id": 1})], None).unwrap();
+        let second = store
+            .write(vec![json!({"id": 2})], Some(&first.version_id))
+            .unwrap();
+        let third = store
+            .write(vec![json!({"id": 1})], Some(&second.version_id))
+            .unwrap();
+
+        assert_eq!(second.parent.as_deref(), Some(first.version_id.as_str()));
+        assert_ne!(
+            first.version_id, third.version_id,
+            "Same data with another parent is a new version"
+        );
+        assert_eq!(first.checksum, third.checksum);
+
+        let versions: Vec<u64> = store
+            .list_versions()
+            .unwrap()
+            .iter()
+            .map(|m| m.version)
+            .collect();
+        assert_eq!(versions, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_version_after_removed_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+
+        let first = store.write(vec![json!({"id": 1})], None).unwrap();
+        let second = store.write(vec![json!({"id": 2})], None).unwrap();
+        let third = store.write(vec![json!({"id": 3})], None).unwrap();
+        assert_eq!((first.version, second.version, third.version), (1, 2, 3));
+
+        fs::remove_dir_all(dir.path().join(&second.version_id)).unwrap();
+
+        let fourth = store.write(vec![json!({"id": 4})], None).unwrap();
+        assert_eq!(fourth.version, 4);
+
+        let versions: Vec<u64> = store
+            .list_versions()
+            .unwrap()
+            .iter()
+            .map(|m| m.version)
+            .collect();
+        assert_eq!(versions, vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn test_concurrent_writers_get_distinct_versions() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+        let barrier = std::sync::Barrier::new(8);
+
+        let mut versions: Vec<u64> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|thread| {
+                    let (store, barrier) = (&store, &barrier);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        (0..8)
+                            .map(|i| {
+                                store
+                                    .write(vec![json!({"thread": thread, "id": i})], None)
+                                    .unwrap()
+                                    .version
+                            })
+                            .collect::<Vec<u64>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect()
+        });
+        versions.sort();
+
+        assert_eq!(versions, (1..=64).collect::<Vec<u64>>());
+        assert_eq!(
+            store
+                .list_versions()
+                .unwrap()
+                .iter()
+                .map(|m| m.version)
+                .collect::<Vec<u64>>(),
+            versions
+        );
+    }
+
+    #[test]
+    fn test_identical_snapshot_is_reused() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+
+        let first = store.write(vec![json!({"id": 1})], None).unwrap();
+        let again = store.write(vec![json!({"id": 1})], None).unwrap();
+
+        assert_eq!(first, again);
+        assert_eq!(store.list_versions().unwrap().len(), 1);
+        assert!(
+            fs::read_dir(dir.path()).unwrap().all(|e| !e
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with(TMP_PREFIX)),
+            "Temporary directory should be removed"
+        );
+    }
+
+    #[test]
+    fn test_commit_race_reuses_existing_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+        let existing = store.write(vec![json!({"id": 1})], None).unwrap();
+
+        // Same snapshot prepared by a concurrent writer that lost the race
+        let tmp_dir = dir.path().join(format!("{TMP_PREFIX}race"));
+        fs::create_dir(&tmp_dir).unwrap();
+        fs::write(tmp_dir.join(DATA_FILE), "{\"id\":1}\n").unwrap();
+        let late = SnapshotManifest {
+            version: existing.version + 1,
+            ..existing.clone()
+        };
+
+        assert_eq!(store.commit(&tmp_dir, late).unwrap(), existing);
+        assert!(!tmp_dir.exists());
+        assert_eq!(store.list_versions().unwrap(), vec![existing]);
+    }
+
+    #[test]
+    fn test_missing_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+
+        assert!(matches!(
+            store.reader("unknown"),
+            Err(SnapshotError::NotFound(_))
+        ));
+        assert!(matches!(
+            store.write(vec![json!({"id": 1})], Some("unknown")),
+            Err(SnapshotError::NotFound(_))
+        ));
+        assert!(store.list_versions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_corrupted_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let store = SnapshotStore::new(dir.path());
+
+        let manifest = store.write(vec![json!({"id": 1})], None).unwrap();
+        fs::write(
+            dir.path().join(&manifest.version_id).join(DATA_FILE),
+            "{\"id\": 2}\n",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            store.verify(&manifest.version_id),
+            Err(SnapshotError::ChecksumMismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TransformError {
+    #[error("Invalid mapping: {0}")]
+    InvalidMapping(String),
+    #[error("Cast error: {0}")]
+    CastError(String),
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+use super::{Transform, TransformError};
+
+/// Type a mapped value is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cast {
+    String,
+    Integer,
+    Float,
+    Boolean,
+}
+
+impl Cast {
+    /// Parses a cast name, an empty name meaning no cast.
+    fn parse(name: &str) -> Result<Option<Self>, TransformError> {
+        match name {
+            "" => Ok(None),
+            "string" => Ok(Some(Cast::String)),
+            "integer" => Ok(Some(Cast::Integer)),
+            "float" => Ok(Some(Cast::Float)),
+            "boolean" => Ok(Some(Cast::Boolean)),
+            other => Err(TransformError::InvalidMapping(format!(
+                "unknown cast `{other}`"
+            ))),
+        }
+    }
+
+    /// Converts a value, returning `None` if the value cannot be represented. `null` values are kept as is.
+    fn apply(self, value: Value) -> Option<Value> {
+        match (self, value) {
+            (_, Value::Null) => Some(Value::Null),
+            (Cast::String, Value::String(s)) => Some(Value::String(s)),
+            (Cast::String, other) => Some(Value::String(other.to_string())),
+            (Cast::Integer, Value::Number(n)) => n
+                .as_i64()
+                .or_else(|| {
+                    // `i64::MAX as f64` rounds up to 2^63, which is already out of range
+                    n.as_f64()
+                        .filter(|f| {
+                            f.fract() == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(f)
+                        })
+                        .map(|f| f as i64)
+                })
+                .map(Value::from),
+            (Cast::Integer, Value::String(s)) => s.trim().parse::<i64>().ok().map(Value::from),
+            (Cast::Integer, Value::Bool(b)) => Some(Value::from(b as i64)),
+            (Cast::Float, Value::Number(n)) => {
+                n.as_f64().and_then(Number::from_f64).map(Value::Number)
+            }
+            (Cast::Float, Value::String(s)) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map(Value::Number),
+            (Cast::Float, Value::Bool(b)) => Some(Value::from(if b { 1.0 } else { 0.0 })),
+            (Cast::Boolean, Value::Bool(b)) => Some(Value::Bool(b)),
+            (Cast::Boolean, Value::Number(n)) => n.as_f64().map(|f| Value::Bool(f != 0.0)),
+            (Cast::Boolean, Value::String(s)) => match s.trim().to_lowercase().as_str() {
+                "true" | "yes" | "1" => Some(Value::Bool(true)),
+                "false" | "no" | "0" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parsed mapping of an output field.
+#[derive(Debug, Clone)]
+struct MappingRule {
+    /// Name of the output field
+    field: String,
+    /// Path of the source value, split on dots
+    path: Vec<String>,
+    /// Optional cast applied to the value
+    cast: Option<Cast>,
+    /// Optional value used when the source is missing or `null`
+    default: Option<Value>,
+}
+
+impl MappingRule {
+    /// Parses a `source_path | cast | default` spec.
+    ///
+    /// The default is read as JSON when possible (`0`, `true`, `"n/a"`), and as a raw string otherwise.
+    fn parse(field: &str, spec: &str) -> Result<Self, TransformError> {
+        let mut parts = spec.splitn(3, '|').map(str::trim);
+
+        let source = parts.next().unwrap_or_default();
+        if source.is_empty() || source.split('.').any(str::is_empty) {
+            return Err(TransformError::InvalidMapping(format!(
+                "invalid source path `{source}` for field `{field}`"
+            )));
+        }
+
+        let cast = Cast::parse(parts.next().unwrap_or_default())?;
+        let default = parts
+            .next()
+            .filter(|d| !d.is_empty())
+            .map(|d| serde_json::from_str(d).unwrap_or_else(|_| Value::String(d.to_string())));
+
+        Ok(MappingRule {
+            field: field.to_string(),
+            path: source.split('.').map(str::to_string).collect(),
+            cast,
+            default,
+        })
+    }
+
+    /// Resolves the source path in an item. Numeric segments index arrays.
+    fn resolve<'a>(&self, item: &'a Value) -> Option<&'a Value> {
+        self.path
+            .iter()
+            .try_fold(item, |value, segment| match value {
+                Value::Object(map) => map.get(segment),
+                Value::Array(values) => segment.parse::<usize>().ok().and_then(|i| values.get(i)),
+                _ => None,
+            })
+    }
+
+    /// Computes the output value of the rule for an item.
+    fn apply(&self, item: &Value) -> Result<Value, TransformError> {
+        let value = match (self.resolve(item), &self.default) {
+            (None | Some(Value::Null), Some(default)) => default.clone(),
+            (Some(value), _) => value.clone(),
+            (None, None) => Value::Null,
+        };
+
+        match self.cast {
+            None => Ok(value),
+            Some(cast) => cast.apply(value.clone()).ok_or_else(|| {
+                TransformError::CastError(format!(
+                    "cannot cast {value} to {cast:?} for field `{}`",
+                    self.field
+                ))
+            }),
+        }
+    }
+}
+
+/// Struct representing a transform remapping items with a declarative spec.
+///
+/// Each output field is described by a compact `source_path | cast | default` spec, replacing chains of
+/// select, rename, cast, default and flatten steps for simple remapping jobs:
+/// - `source_path`: dot separated path of the source value, numeric segments indexing arrays (`lines.0.sku`)
+/// - `cast` (optional): one of `string`, `integer`, `float` or `boolean`
+/// - `default` (optional): value used when the source is missing or `null`, read as JSON when possible
+///
+/// Only mapped fields are returned. Missing values without a default are returned as `null`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustifile::transforms::Transform;
+/// use serde_json::json;
+///
+/// let mut transform: Box<dyn Transform> = serde_json::from_value(json!({
+///     "type": "mapping",
+///     "mapping": {
+///         "id": "order.id | integer",
+///         "sku": "order.lines.0.sku",
+///         "currency": "order.currency | string | EUR"
+///     }
+/// })).unwrap();
+///
+/// let item = transform
+///     .transform(json!({"order": {"id": "42", "lines": [{"sku": "A-1"}]}}))
+///     .unwrap();
+/// assert_eq!(item, json!({"id": 42, "sku": "A-1", "currency": "EUR"}));
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MappingTransform {
+    /// Specs of the output fields, by output field name
+    mapping: BTreeMap<String, String>,
+
+    /// Parsed rules. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _rules: Option<Vec<MappingRule>>,
+}
+
+impl MappingTransform {
+    /// Parses the mapping specs into rules.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransformError>` - Returns `Ok(())` if all specs are valid, or an error for the first invalid one.
+    fn init_rules(&mut self) -> Result<(), TransformError> {
+        let rules = self
+            .mapping
+            .iter()
+            .map(|(field, spec)| MappingRule::parse(field, spec))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tracing::debug!("Initialized mapping transform with rules : {:?}", rules);
+
+        self._rules = Some(rules);
+
+        Ok(())
+    }
+}
+
+/// Implementation of the `Transform` trait for `MappingTransform`.
+#[typetag::serde(name = "mapping")]
+impl Transform for MappingTransform {
+    /// Builds a new item from the mapping specs.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Value, TransformError>` - Returns the mapped item, or an error if a spec is invalid or a value cannot be cast.
+    fn transform(&mut self, item: Value) -> Result<Value, TransformError> {
+        if self._rules.is_none() {
+            self.init_rules()?;
+        }
+
+        let mut output = Map::new();
+        for rule in self._rules.iter().flatten() {
+            output.insert(rule.field.clone(), rule.apply(&item)?);
+        }
+
+        Ok(Value::Object(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn get_transform(mapping: Value) -> MappingTransform {
+        serde_json::from_value(json!({ "mapping": mapping })).unwrap()
+    }
+
+    #[test]
+    fn test_mapping() {
+        let mut transform = get_transform(json!({
+            "name": "product.name",
+            "price": "product.price | float",
+            "quantity": "stock.0.quantity | integer | 0",
+            "in_stock": "product.available | boolean | false",
+            "label": "product.label | | \"n/a\"",
+            "category": "product.category | string | misc",
+            "missing": "product.missing"
+        }));
+
+        let item = transform
+            .transform(json!({
+                "product": {"name": "Chair", "price": "10.5", "available": "yes", "label": null},
+                "stock": [{"quantity": 3.0}]
+            }))
+            .unwrap();
+
+        assert_eq!(
+            item,
+            json!({
+                "name": "Chair",
+                "price": 10.5,
+                "quantity": 3,
+                "in_stock": true,
+                "label": "n/a",
+                "category": "misc",
+                "missing": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_default_is_cast() {
+        let mut transform = get_transform(json!({"quantity": "quantity | integer | \"12\""}));
+
+        let item = transform.transform(json!({})).unwrap();
+        assert_eq!(item, json!({"quantity": 12}));
+    }
+
+    #[test]
+    fn test_cast_error() {
+        let mut transform = get_transform(json!({"price": "price | integer"}));
+
+        assert!(matches!(
+            transform.transform(json!({"price": 10.5})),
+            Err(TransformError::CastError(_))
+        ));
+        assert!(matches!(
+            transform.transform(json!({"price": "ten"})),
+            Err(TransformError::CastError(_))
+        ));
+    }
+
+    #[test]
+    fn test_integer_cast_out_of_range() {
+        let mut transform = get_transform(json!({"a": "a | integer", "b": "b | integer"}));
+
+        assert!(matches!(
+            transform.transform(json!({"a": 1e20, "b": 1})),
+            Err(TransformError::CastError(_))
+        ));
+        assert!(matches!(
+            transform.transform(json!({"a": 1, "b": u64::MAX})),
+            Err(TransformError::CastError(_))
+        ));
+        assert_eq!(
+            transform
+                .transform(json!({"a": -9.0e18, "b": i64::MAX}))
+                .unwrap(),
+            json!({"a": -9_000_000_000_000_000_000i64, "b": i64::MAX})
+        );
+    }
+
+    #[test]
+    fn test_invalid_mapping() {
+        let mut unknown_cast = get_transform(json!({"price": "price | decimal"}));
+        assert!(matches!(
+            unknown_cast.transform(json!({})),
+            Err(TransformError::InvalidMapping(_))
+        ));
+
+        let mut empty_path = get_transform(json!({"price": " | float"}));
+        assert!(matches!(
+            empty_path.transform(json!({})),
+            Err(TransformError::InvalidMapping(_))
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+mod errors;
+mod mapping;
+
+use serde_json::Value;
+
+pub use errors::TransformError;
+pub use mapping::MappingTransform;
+
+/// Trait defining the functionalities of an item transform.
+///
+/// This trait uses the `typetag::serde` macro to enable polymorphic deserialization.
+#[typetag::serde(tag = "type")]
+pub trait Transform {
+    /// Transforms an item.
+    ///
+    /// This method is called iteratively with each `serde_json::Value` returned by a reader.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Value, TransformError>` - Returns the transformed item, or an error if the item cannot be transformed.
+    fn transform(&mut self, item: Value) -> Result<Value, TransformError>;
+}
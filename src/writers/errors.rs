@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WriterError {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("Writer error: {0}")]
+    InvalidItem(&'static str),
+    #[error("Value overflows column {0}")]
+    OverflowError(String),
+    #[error("Preflight failed: {0}")]
+    PreflightError(String),
+}
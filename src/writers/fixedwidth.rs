@@ -0,0 +1,390 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Encoding, FileWriter, WriterError, check_writable};
+
+/// Default padding function for fixed width columns.
+///
+/// Returns a space (` `) as the default padding character.
+fn default_padding() -> char {
+    ' '
+}
+
+/// Default line terminator function for the fixed width writer.
+///
+/// Returns a new line (`\n`) as the default line terminator.
+fn default_line_terminator() -> String {
+    "\n".to_string()
+}
+
+/// Alignment of a value within its column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Alignment {
+    /// Value is written at the start of the column, padding is added after it
+    #[default]
+    Left,
+    /// Value is written at the end of the column, padding is added before it
+    Right,
+}
+
+/// Behavior when a value is longer than its column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverflowPolicy {
+    /// The item is rejected with an `OverflowError`
+    #[default]
+    Error,
+    /// Strings are cut to the column width, keeping their first characters. Numbers are never cut, as it would
+    /// change their value, and are still rejected with an `OverflowError`
+    Truncate,
+}
+
+/// Struct describing a column of a fixed width file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedWidthColumn {
+    /// Name of the item field written in this column
+    pub name: String,
+
+    /// Width of the column, in characters
+    pub width: usize,
+
+    /// Alignment of the value within the column. Defaults to `left`.
+    #[serde(default)]
+    pub align: Alignment,
+
+    /// Character used to fill the column. Defaults to a space (` `).
+    #[serde(default = "default_padding")]
+    pub padding: char,
+
+    /// Number of decimals written for numeric values. Numbers are written as is when not set.
+    #[serde(default)]
+    pub decimals: Option<usize>,
+}
+
+impl FixedWidthColumn {
+    /// Formats a value to the width of the column.
+    ///
+    /// Missing and `null` values are written as padding only. When numbers are right aligned and padded with zeros,
+    /// the sign is kept in front of the padding (`-0042`).
+    fn format(
+        &self,
+        value: Option<&Value>,
+        overflow: OverflowPolicy,
+    ) -> Result<String, WriterError> {
+        let mut text = match value {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Number(n)) => match (self.decimals, n.as_f64()) {
+                (Some(decimals), Some(f)) => format!("{f:.decimals$}"),
+                _ => n.to_string(),
+            },
+            Some(other) => other.to_string(),
+        };
+
+        let len = text.chars().count();
+        if len > self.width {
+            return match overflow {
+                OverflowPolicy::Truncate if !matches!(value, Some(Value::Number(_))) => {
+                    Ok(text.chars().take(self.width).collect())
+                }
+                _ => Err(WriterError::OverflowError(self.name.clone())),
+            };
+        }
+
+        let padding: String = std::iter::repeat_n(self.padding, self.width - len).collect();
+        Ok(match self.align {
+            Alignment::Left => text + &padding,
+            Alignment::Right
+                if self.padding == '0'
+                    && matches!(value, Some(Value::Number(_)))
+                    && text.starts_with('-') =>
+            {
+                text.insert_str(1, &padding);
+                text
+            }
+            Alignment::Right => padding + &text,
+        })
+    }
+}
+
+/// Struct representing a fixed width writer.
+///
+/// This struct is used to write JSON objects as lines of fixed width columns, as expected by mainframe era interfaces.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FixedWidthWriter {
+    /// Path for the file to write
+    file_path: String,
+
+    /// Columns of each line, in order
+    columns: Vec<FixedWidthColumn>,
+
+    /// Behavior when a value is longer than its column. Defaults to `error`.
+    #[serde(default)]
+    overflow: OverflowPolicy,
+
+    /// String written after each line. Defaults to a new line (`\n`).
+    #[serde(default = "default_line_terminator")]
+    line_terminator: String,
+
+    /// Encoding of the file, `utf-8` or `utf-16le`. Defaults to `utf-8`.
+    #[serde(default)]
+    encoding: Encoding,
+
+    /// The internal file writer. This field is skipped during serialization and deserialization.
+    #[serde(skip)]
+    _writer: Option<BufWriter<File>>,
+}
+
+impl FixedWidthWriter {
+    /// Returns the file writer, initializing it on first use.
+    ///
+    /// This method creates the file specified by `file_path`, truncating it if it already exists, and writes the
+    /// byte order mark of the encoding.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<&mut BufWriter<File>, WriterError>` - Returns the file writer, or an error if the file cannot be created.
+    fn writer(&mut self) -> Result<&mut BufWriter<File>, WriterError> {
+        let writer = match self._writer.take() {
+            Some(writer) => writer,
+            None => {
+                let mut writer = BufWriter::new(File::create(&self.file_path)?);
+                writer.write_all(self.encoding.bom())?;
+                tracing::debug!("Initialized fixed width writer with config : {:?}", self);
+                writer
+            }
+        };
+
+        Ok(self._writer.insert(writer))
+    }
+}
+
+/// Implementation of the `FileWriter` trait for `FixedWidthWriter`.
+///
+/// This implementation allows `FixedWidthWriter` to be used as a file writer.
+#[typetag::serde(name = "fixedwidth")]
+impl FileWriter for FixedWidthWriter {
+    /// Writes an item as a fixed width line.
+    ///
+    /// The whole line is formatted before being written, so an item rejected because of an overflow
+    /// leaves the file untouched.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), WriterError>` - Returns `Ok(())` if the line is written, or an error if the item is not an object,
+    ///   a value overflows its column, or the file cannot be written.
+    fn write_item(&mut self, item: &Value) -> Result<(), WriterError> {
+        let Value::Object(record) = item else {
+            return Err(WriterError::InvalidItem(
+                "FixedWidthWriter expects json objects",
+            ));
+        };
+
+        let mut line = String::new();
+        for column in &self.columns {
+            line.push_str(&column.format(record.get(&column.name), self.overflow)?);
+        }
+        line.push_str(&self.line_terminator);
+
+        let encoding = self.encoding;
+        Ok(self.writer()?.write_all(&encoding.encode(&line))?)
+    }
+
+    /// Flushes buffered lines to the file.
+    ///
+    /// The file is created, or truncated, even if no item was written, so an empty run does not leave
+    /// the content of a previous run in place.
+    fn flush(&mut self) -> Result<(), WriterError> {
+        Ok(self.writer()?.flush()?)
+    }
+
+    /// Checks that the file can be created or written.
+    fn preflight(&self) -> Result<(), WriterError> {
+        check_writable(&self.file_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::{NamedTempFile, TempDir};
+
+    use super::*;
+
+    fn get_writer(path: &str, overflow: OverflowPolicy) -> FixedWidthWriter {
+        serde_json::from_value(json!({
+            "file_path": path,
+            "overflow": overflow,
+            "columns": [
+                {"name": "code", "width": 6},
+                {"name": "label", "width": 8, "padding": "."},
+                {"name": "amount", "width": 8, "align": "right", "padding": "0", "decimals": 2},
+                {"name": "qty", "width": 4, "align": "right"}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_writing_file() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut writer = get_writer(path, OverflowPolicy::Error);
+
+        writer
+            .write_item(&json!({"code": "A1", "label": "apple", "amount": 12.5, "qty": 3}))
+            .unwrap();
+        writer
+            .write_item(&json!({"code": "B22", "label": null, "amount": -4, "qty": 120}))
+            .unwrap();
+        writer.write_item(&json!({"code": "C333"})).unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "A1    apple...00012.50   3\nB22   ........-0004.00 120\nC333  ........00000000    \n"
+        );
+    }
+
+    #[test]
+    fn test_writing_no_item() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        std::fs::write(path, "previous run\n").unwrap();
+
+        let mut writer = get_writer(path, OverflowPolicy::Error);
+        writer.flush().unwrap();
+
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_writing_utf16le() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut writer: FixedWidthWriter = serde_json::from_value(json!({
+            "file_path": path,
+            "encoding": "utf-16le",
+            "line_terminator": "\r\n",
+            "columns": [{"name": "city", "width": 6}]
+        }))
+        .unwrap();
+
+        writer.write_item(&json!({"city": "Liège"})).unwrap();
+        writer.flush().unwrap();
+
+        let mut expected = vec![0xFF, 0xFE];
+        expected.extend("Liège \r\n".encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(std::fs::read(path).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_overflow_error() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut writer = get_writer(path, OverflowPolicy::Error);
+
+        let result = writer.write_item(&json!({"code": "A1", "label": "pineapples"}));
+        assert!(matches!(result, Err(WriterError::OverflowError(column)) if column == "label"));
+
+        writer.write_item(&json!({"code": "A2"})).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "A2    ........00000000    \n"
+        );
+    }
+
+    #[test]
+    fn test_overflow_truncate() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut writer = get_writer(path, OverflowPolicy::Truncate);
+
+        writer
+            .write_item(&json!({"code": "LONGCODE", "label": "pineapples", "amount": 1, "qty": 1}))
+            .unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "LONGCOpineappl00001.00   1\n"
+        );
+    }
+
+    #[test]
+    fn test_overflow_truncate_number() {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap();
+        let mut writer = get_writer(path, OverflowPolicy::Truncate);
+
+        let result = writer.write_item(&json!({"code": "A1", "amount": 123456.78}));
+        assert!(matches!(result, Err(WriterError::OverflowError(column)) if column == "amount"));
+
+        let result = writer.write_item(&json!({"code": "A1", "qty": 12345}));
+        assert!(matches!(result, Err(WriterError::OverflowError(column)) if column == "qty"));
+
+        writer.flush().unwrap();
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_invalid_item() {
+        let file = NamedTempFile::new().unwrap();
+        let mut writer = get_writer(file.path().to_str().unwrap(), OverflowPolicy::Error);
+
+        assert!(matches!(
+            writer.write_item(&json!([1, 2])),
+            Err(WriterError::InvalidItem(_))
+        ));
+    }
+
+    #[test]
+    fn test_preflight() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("existing.txt");
+        let path = file.to_str().unwrap();
+        std::fs::write(path, "existing content\n").unwrap();
+
+        assert!(get_writer(path, OverflowPolicy::Error).preflight().is_ok());
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "existing content\n",
+            "Preflight should not modify the file"
+        );
+
+        let new_file = dir.path().join("new.txt");
+        assert!(
+            get_writer(new_file.to_str().unwrap(), OverflowPolicy::Error)
+                .preflight()
+                .is_ok()
+        );
+        assert_eq!(
+            std::fs::read_dir(dir.path()).unwrap().count(),
+            1,
+            "Preflight should not create any file"
+        );
+
+        let under_file = file.join("file.txt");
+        assert!(matches!(
+            get_writer(under_file.to_str().unwrap(), OverflowPolicy::Error).preflight(),
+            Err(WriterError::PreflightError(_))
+        ));
+
+        assert!(
+            get_writer("/invalid/dir/file.txt", OverflowPolicy::Error)
+                .preflight()
+                .is_err()
+        );
+        assert!(matches!(
+            get_writer(env!("CARGO_MANIFEST_DIR"), OverflowPolicy::Error).preflight(),
+            Err(WriterError::PreflightError(_))
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+mod errors;
+mod fixedwidth;
+
+use std::{borrow::Cow, path::Path};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub use errors::WriterError;
+pub use fixedwidth::{Alignment, FixedWidthColumn, FixedWidthWriter, OverflowPolicy};
+
+/// Trait defining the functionalities of a file writer.
+///
+/// This trait uses the `typetag::serde` macro to enable polymorphic deserialization.
+#[typetag::serde(tag = "type")]
+pub trait FileWriter {
+    /// Writes an item to the file.
+    ///
+    /// This method is called iteratively with each `serde_json::Value` to write. In the context of this trait,
+    /// an item can be written as one or multiple lines within the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), WriterError>` - Returns `Ok(())` if the item is written, or an error if it cannot be written.
+    fn write_item(&mut self, item: &Value) -> Result<(), WriterError>;
+
+    /// Flushes buffered items to the file.
+    ///
+    /// This method must be called once all items are written, as buffered items may otherwise be lost silently.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), WriterError>` - Returns `Ok(())` if the file is flushed, or an error if it cannot be written.
+    fn flush(&mut self) -> Result<(), WriterError>;
+
+    /// Checks that the writer can be used, without writing any item.
+    ///
+    /// This method verifies connectivity and permissions (e.g. the file can be created or written), so problems can be
+    /// detected before scheduling the actual write. The default implementation performs no check.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), WriterError>` - Returns `Ok(())` if the writer is ready, or the error preventing it from writing.
+    fn preflight(&self) -> Result<(), WriterError> {
+        Ok(())
+    }
+}
+
+/// Character encoding of the files written by text writers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Encoding {
+    /// UTF-8, without byte order mark
+    #[default]
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// UTF-16 little endian, starting with a byte order mark, as expected by some Windows systems
+    #[serde(rename = "utf-16le")]
+    Utf16Le,
+}
+
+impl Encoding {
+    /// Returns the bytes written at the start of a file.
+    fn bom(self) -> &'static [u8] {
+        match self {
+            Encoding::Utf8 => &[],
+            Encoding::Utf16Le => &[0xFF, 0xFE],
+        }
+    }
+
+    /// Encodes text to the bytes written to a file.
+    fn encode(self, text: &str) -> Cow<'_, [u8]> {
+        match self {
+            Encoding::Utf8 => Cow::Borrowed(text.as_bytes()),
+            Encoding::Utf16Le => {
+                Cow::Owned(text.encode_utf16().flat_map(u16::to_le_bytes).collect())
+            }
+        }
+    }
+}
+
+/// Checks that `path` can be written, without modifying it.
+///
+/// An existing file must be a regular file the current user can write. Otherwise, its parent directory must exist
+/// and allow the current user to create files. Permissions are checked with `access(2)`, so no file is opened or
+/// created, and consumers polling the target directory see no activity.
+fn check_writable(path: &str) -> Result<(), WriterError> {
+    let path = Path::new(path);
+
+    if path.exists() {
+        if !path.is_file() {
+            return Err(WriterError::PreflightError(format!(
+                "{} is not a file",
+                path.display()
+            )));
+        }
+        return can_write(path).map_err(|e| {
+            WriterError::PreflightError(format!("{} is not writable: {e}", path.display()))
+        });
+    }
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if !parent.metadata()?.is_dir() {
+        return Err(WriterError::PreflightError(format!(
+            "{} is not a directory",
+            parent.display()
+        )));
+    }
+
+    can_write(parent).map_err(|e| {
+        WriterError::PreflightError(format!(
+            "{} is not a writable directory: {e}",
+            parent.display()
+        ))
+    })
+}
+
+/// Checks that the current user has write permission on `path`.
+#[cfg(unix)]
+fn can_write(path: &Path) -> std::io::Result<()> {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let path = CString::new(path.as_os_str().as_bytes())?;
+    // SAFETY: `path` is a valid nul terminated string that outlives the call
+    if unsafe { libc::access(path.as_ptr(), libc::W_OK) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Checks that `path` is not read only.
+#[cfg(not(unix))]
+fn can_write(path: &Path) -> std::io::Result<()> {
+    if path.metadata()?.permissions().readonly() {
+        return Err(std::io::ErrorKind::PermissionDenied.into());
+    }
+
+    Ok(())
+}